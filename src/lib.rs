@@ -13,6 +13,28 @@ pub trait EwmaFilter<T> {
 
     /// returns the local minima and maxima
     fn local_range(&self) -> Range<T>;
+
+    /// Folds an iterator of samples through `push_sample`, returning the final average.
+    fn push_iter<I>(&mut self, samples: I) -> T
+    where
+        Self: Sized,
+        I: IntoIterator<Item = T>,
+    {
+        let mut average = self.ewma_average();
+        for sample in samples {
+            average = self.push_sample(sample);
+        }
+        average
+    }
+}
+
+/// Tracks a fading (exponentially weighted) measure of spread alongside the mean.
+pub trait EwmaStats<T> {
+    /// Returns the exponentially weighted moving variance of the series
+    fn ewma_variance(&self) -> T;
+
+    /// Returns the exponentially weighted moving standard deviation of the series
+    fn ewma_std_dev(&self) -> T;
 }
 
 /// Implements exponential weighted moving average of time series samples,
@@ -26,6 +48,8 @@ pub struct FloatSeriesEwmaFilter<T> {
     local_max: T,
     /// exponentially weighted moving average
     average: T,
+    /// exponentially weighted moving variance
+    variance: T,
     /// weighting factor-- bigger alpha causes faster fade of old values
     alpha: T,
 }
@@ -41,12 +65,39 @@ where
             local_min: T::zero(),
             local_max: T::zero(),
             average: T::zero(),
+            variance: T::zero(),
         }
     }
 
     pub fn default() -> Self {
         Self::new(T::from(0.01).unwrap())
     }
+
+    /// Resets the filter to its initial seed state, as if no samples had been pushed.
+    pub fn reset(&mut self) {
+        self.sample_count = 0;
+        self.local_min = T::zero();
+        self.local_max = T::zero();
+        self.average = T::zero();
+        self.variance = T::zero();
+    }
+
+    /// Pushes `value` if present; on `None`, leaves the mean alone and just
+    /// fades the extrema toward it, handling a dropout without a fake sample.
+    /// Returns the cached average either way.
+    pub fn push_optional_sample(&mut self, value: Option<T>) -> T {
+        match value {
+            Some(new_value) => self.push_sample(new_value),
+            None => {
+                if self.sample_count > 0 {
+                    self.local_max += self.alpha * (self.average - self.local_max);
+                    self.local_min += self.alpha * (self.average - self.local_min);
+                    self.sample_count += 1;
+                }
+                self.average
+            }
+        }
+    }
 }
 
 impl<T> EwmaFilter<T> for FloatSeriesEwmaFilter<T>
@@ -60,8 +111,11 @@ where
             self.local_min = new_value;
             self.local_max = new_value;
             self.average = new_value;
+            self.variance = T::zero();
         } else {
-            self.average += self.alpha * (new_value - self.average);
+            let diff = new_value - self.average;
+            self.average += self.alpha * diff;
+            self.variance = (T::one() - self.alpha) * (self.variance + self.alpha * diff * diff);
 
             // extrema fade toward average
             if new_value > self.local_max {
@@ -90,6 +144,37 @@ where
     }
 }
 
+impl<T> EwmaStats<T> for FloatSeriesEwmaFilter<T>
+where
+    T: Float + core::ops::AddAssign,
+{
+    fn ewma_variance(&self) -> T {
+        self.variance
+    }
+
+    fn ewma_std_dev(&self) -> T {
+        self.variance.sqrt()
+    }
+}
+
+/// One-shot float EWMA over a fixed set of samples, without keeping the filter around.
+pub fn ewma_over<T, I>(samples: I, alpha: T) -> T
+where
+    T: Float + core::ops::AddAssign,
+    I: IntoIterator<Item = T>,
+{
+    FloatSeriesEwmaFilter::new(alpha).push_iter(samples)
+}
+
+/// One-shot integer EWMA over a fixed set of samples, without keeping the filter around.
+pub fn ewma_over_int<T, I>(samples: I, alpha_numerator: T, alpha_denominator: T) -> T
+where
+    T: PrimInt + core::ops::AddAssign,
+    I: IntoIterator<Item = T>,
+{
+    IntSeriesEwmaFilter::new(alpha_numerator, alpha_denominator).push_iter(samples)
+}
+
 pub struct IntSeriesEwmaFilter<T> {
     /// sample count
     sample_count: usize,
@@ -100,6 +185,8 @@ pub struct IntSeriesEwmaFilter<T> {
     local_max: T,
     /// exponentially weighted moving average
     average: T,
+    /// exponentially weighted moving variance
+    variance: T,
     /// weighting factor-- bigger alpha causes faster fade of old values
     alpha_numerator: T,
     alpha_denominator: T,
@@ -117,12 +204,58 @@ where
             local_min: T::zero(),
             local_max: T::zero(),
             average: T::zero(),
+            variance: T::zero(),
         }
     }
 
     pub fn default() -> Self {
         Self::new(T::one(), T::from(100).unwrap())
     }
+
+    /// Resets the filter to its initial seed state, as if no samples had been pushed.
+    pub fn reset(&mut self) {
+        self.sample_count = 0;
+        self.local_min = T::zero();
+        self.local_max = T::zero();
+        self.average = T::zero();
+        self.variance = T::zero();
+    }
+
+    /// Pushes `value` if present; on `None`, leaves the mean alone and just
+    /// fades the extrema toward it, handling a dropout without a fake sample.
+    /// Returns the cached average either way.
+    pub fn push_optional_sample(&mut self, value: Option<T>) -> T {
+        match value {
+            Some(new_value) => self.push_sample(new_value),
+            None => {
+                if self.sample_count > 0 {
+                    // subtract the smaller from the larger explicitly, since T may be
+                    // an unsigned integer type that would underflow on a negative diff
+                    if self.local_max > self.average {
+                        self.local_max = self.local_max
+                            - (self.alpha_numerator * (self.local_max - self.average))
+                                / self.alpha_denominator;
+                    } else if self.local_max < self.average {
+                        self.local_max += (self.alpha_numerator
+                            * (self.average - self.local_max))
+                            / self.alpha_denominator;
+                    }
+
+                    if self.local_min > self.average {
+                        self.local_min = self.local_min
+                            - (self.alpha_numerator * (self.local_min - self.average))
+                                / self.alpha_denominator;
+                    } else if self.local_min < self.average {
+                        self.local_min += (self.alpha_numerator
+                            * (self.average - self.local_min))
+                            / self.alpha_denominator;
+                    }
+                    self.sample_count += 1;
+                }
+                self.average
+            }
+        }
+    }
 }
 
 impl<T> EwmaFilter<T> for IntSeriesEwmaFilter<T>
@@ -136,9 +269,16 @@ where
             self.local_min = new_value;
             self.local_max = new_value;
             self.average = new_value;
+            self.variance = T::zero();
         } else {
-            self.average +=
-                (self.alpha_numerator * (new_value - self.average)) / self.alpha_denominator;
+            let diff = new_value - self.average;
+            self.average += (self.alpha_numerator * diff) / self.alpha_denominator;
+            // same (1 - alpha) * (variance + alpha * diff^2) recurrence as the float
+            // filter, worked through the numerator/denominator fixed-point division;
+            // diff is squared before dividing, so watch for overflow with wide T/large diffs.
+            self.variance = ((self.alpha_denominator - self.alpha_numerator)
+                * (self.variance + (self.alpha_numerator * diff * diff) / self.alpha_denominator))
+                / self.alpha_denominator;
 
             // extrema fade toward average
             if new_value > self.local_max {
@@ -169,10 +309,298 @@ where
     }
 }
 
+impl<T> EwmaStats<T> for IntSeriesEwmaFilter<T>
+where
+    T: PrimInt + core::ops::AddAssign,
+{
+    fn ewma_variance(&self) -> T {
+        self.variance
+    }
+
+    /// Integer square root (Newton's method) of the tracked variance, since
+    /// `PrimInt` has no native `sqrt`.
+    fn ewma_std_dev(&self) -> T {
+        isqrt(self.variance)
+    }
+}
+
+/// Integer square root via Newton's method, used by `IntSeriesEwmaFilter::ewma_std_dev`.
+fn isqrt<T: PrimInt>(value: T) -> T {
+    if value <= T::one() {
+        return value;
+    }
+    let mut x = value;
+    let mut y = (x + T::one()) >> 1;
+    while y < x {
+        x = y;
+        y = (x + value / x) >> 1;
+    }
+    x
+}
+
+/// Implements an EWMA filter whose effective smoothing factor is derived from
+/// the elapsed time between samples via a time constant `tau`, instead of a
+/// fixed per-sample alpha.
+pub struct TimeWeightedEwmaFilter<T> {
+    /// number of samples that have been pushed through the filter
+    sample_count: usize,
+    /// recent minimum value (not global minimum)
+    local_min: T,
+    /// recent maximum value (not global maximum)
+    local_max: T,
+    /// exponentially weighted moving average
+    average: T,
+    /// time constant-- bigger tau causes slower fade of old values
+    tau: T,
+}
+
+impl<T> TimeWeightedEwmaFilter<T>
+where
+    T: Float + core::ops::AddAssign,
+{
+    pub fn new(tau: T) -> Self {
+        Self {
+            sample_count: 0,
+            tau,
+            local_min: T::zero(),
+            local_max: T::zero(),
+            average: T::zero(),
+        }
+    }
+
+    pub fn default() -> Self {
+        Self::new(T::from(100.0).unwrap())
+    }
+
+    /// Pushes the next sample, weighting it by the elapsed time `dt` since the
+    /// previous sample: `effective_alpha = 1 - exp(-dt / tau)`.
+    pub fn push_sample_at(&mut self, new_value: T, dt: T) -> T {
+        if self.sample_count == 0 {
+            //seed the EMWA with the initial value
+            self.local_min = new_value;
+            self.local_max = new_value;
+            self.average = new_value;
+        } else {
+            let effective_alpha = T::one() - (-dt / self.tau).exp();
+            self.average += effective_alpha * (new_value - self.average);
+
+            // extrema fade toward average
+            if new_value > self.local_max {
+                self.local_max = new_value;
+            } else if new_value > self.average {
+                self.local_max += effective_alpha * (new_value - self.local_max);
+            }
+
+            if new_value < self.local_min {
+                self.local_min = new_value;
+            } else if new_value < self.average {
+                self.local_min += effective_alpha * (new_value - self.local_min);
+            }
+        }
+        self.sample_count += 1;
+
+        self.average
+    }
+}
+
+impl<T> EwmaFilter<T> for TimeWeightedEwmaFilter<T>
+where
+    T: Float + core::ops::AddAssign,
+{
+    /// Pushes a sample assuming one unit of elapsed time since the previous sample.
+    /// Use `push_sample_at` directly for irregularly spaced samples.
+    fn push_sample(&mut self, new_value: T) -> T {
+        self.push_sample_at(new_value, T::one())
+    }
+
+    fn ewma_average(&self) -> T {
+        self.average
+    }
+
+    fn local_range(&self) -> Range<T> {
+        self.local_min..self.local_max
+    }
+}
+
+/// Implements an exponentially weighted moving average with bias correction
+/// for the warm-up period (the "adjusted" mode also seen in pandas' `ewm`):
+/// rather than seeding the average with the first sample, early outputs are
+/// normalized by the running sum of weights, so they aren't anchored to
+/// whichever sample happened to arrive first.
+pub struct AdjustedEwmaFilter<T> {
+    /// number of samples that have been pushed through the filter
+    sample_count: usize,
+    /// recent minimum value (not global minimum)
+    local_min: T,
+    /// recent maximum value (not global maximum)
+    local_max: T,
+    /// running weighted sum of samples (divide by `weight` for the adjusted average)
+    ewma_old: T,
+    /// running sum of weights used to normalize `ewma_old`
+    weight: T,
+    /// weighting factor-- bigger alpha causes faster fade of old values
+    alpha: T,
+    /// cached `1 - alpha`
+    one_sub_alpha: T,
+}
+
+impl<T> AdjustedEwmaFilter<T>
+where
+    T: Float + core::ops::AddAssign,
+{
+    pub fn new(alpha: T) -> Self {
+        Self {
+            sample_count: 0,
+            alpha,
+            one_sub_alpha: T::one() - alpha,
+            local_min: T::zero(),
+            local_max: T::zero(),
+            ewma_old: T::zero(),
+            weight: T::zero(),
+        }
+    }
+
+    pub fn default() -> Self {
+        Self::new(T::from(0.01).unwrap())
+    }
+}
+
+impl<T> EwmaFilter<T> for AdjustedEwmaFilter<T>
+where
+    T: Float + core::ops::AddAssign,
+{
+    /// Returns the bias-corrected exponentially weighted moving average
+    fn push_sample(&mut self, new_value: T) -> T {
+        if self.sample_count == 0 {
+            self.local_min = new_value;
+            self.local_max = new_value;
+        }
+
+        self.weight += self.one_sub_alpha.powi(self.sample_count as i32);
+        self.ewma_old = self.ewma_old * self.one_sub_alpha + new_value;
+        let average = self.ewma_average();
+
+        if self.sample_count > 0 {
+            // extrema fade toward average
+            if new_value > self.local_max {
+                self.local_max = new_value;
+            } else if new_value > average {
+                self.local_max += self.alpha * (new_value - self.local_max);
+            }
+
+            if new_value < self.local_min {
+                self.local_min = new_value;
+            } else if new_value < average {
+                self.local_min += self.alpha * (new_value - self.local_min);
+            }
+        }
+        self.sample_count += 1;
+
+        average
+    }
+
+    fn ewma_average(&self) -> T {
+        self.ewma_old / self.weight
+    }
+
+    fn local_range(&self) -> Range<T> {
+        self.local_min..self.local_max
+    }
+}
+
+/// Wraps three `EwmaFilter` instances over the same stream, each configured
+/// with a distinct alpha, so a single sample can feed short-, mid-, and
+/// long-horizon smoothing at once.
+pub struct MultiScaleEwmaFilter<F> {
+    short: F,
+    mid: F,
+    long: F,
+}
+
+impl<F> MultiScaleEwmaFilter<F> {
+    pub fn new(short: F, mid: F, long: F) -> Self {
+        Self { short, mid, long }
+    }
+
+    /// Pushes `new_value` into all three filters, returning the mid-horizon average.
+    pub fn push_sample<T>(&mut self, new_value: T) -> T
+    where
+        F: EwmaFilter<T>,
+        T: Copy,
+    {
+        self.short.push_sample(new_value);
+        self.long.push_sample(new_value);
+        self.mid.push_sample(new_value)
+    }
+
+    /// Fast-reacting, short-horizon view of the stream.
+    pub fn short(&self) -> &F {
+        &self.short
+    }
+
+    /// Medium-horizon view of the stream.
+    pub fn mid(&self) -> &F {
+        &self.mid
+    }
+
+    /// Slow-fading, long-horizon view of the stream.
+    pub fn long(&self) -> &F {
+        &self.long
+    }
+}
+
+impl<T> MultiScaleEwmaFilter<FloatSeriesEwmaFilter<T>>
+where
+    T: Float + core::ops::AddAssign,
+{
+    /// Builds short/mid/long `FloatSeriesEwmaFilter`s from the given alphas,
+    /// e.g. `DEFAULT_EWMA_ALPHA_SHORT`/`_MID`/`_LONG`.
+    pub fn with_float_alphas(short_alpha: T, mid_alpha: T, long_alpha: T) -> Self {
+        Self::new(
+            FloatSeriesEwmaFilter::new(short_alpha),
+            FloatSeriesEwmaFilter::new(mid_alpha),
+            FloatSeriesEwmaFilter::new(long_alpha),
+        )
+    }
+}
+
+impl<T> MultiScaleEwmaFilter<IntSeriesEwmaFilter<T>>
+where
+    T: PrimInt + core::ops::AddAssign,
+{
+    /// Builds short/mid/long `IntSeriesEwmaFilter`s from the given alpha fractions.
+    pub fn with_int_alphas(
+        short_alpha_numerator: T,
+        short_alpha_denominator: T,
+        mid_alpha_numerator: T,
+        mid_alpha_denominator: T,
+        long_alpha_numerator: T,
+        long_alpha_denominator: T,
+    ) -> Self {
+        Self::new(
+            IntSeriesEwmaFilter::new(short_alpha_numerator, short_alpha_denominator),
+            IntSeriesEwmaFilter::new(mid_alpha_numerator, mid_alpha_denominator),
+            IntSeriesEwmaFilter::new(long_alpha_numerator, long_alpha_denominator),
+        )
+    }
+}
+
+/// Commonly used alphas for simultaneous short/mid/long horizon smoothing of one stream.
+pub const DEFAULT_EWMA_ALPHA_SHORT: f32 = 0.1;
+pub const DEFAULT_EWMA_ALPHA_MID: f32 = 0.01;
+pub const DEFAULT_EWMA_ALPHA_LONG: f32 = 0.001;
+
 #[cfg(test)]
 mod tests {
-    use crate::{EwmaFilter, FloatSeriesEwmaFilter, IntSeriesEwmaFilter};
+    extern crate std;
+
+    use crate::{
+        ewma_over, ewma_over_int, AdjustedEwmaFilter, EwmaFilter, EwmaStats,
+        FloatSeriesEwmaFilter, IntSeriesEwmaFilter, MultiScaleEwmaFilter, TimeWeightedEwmaFilter,
+        DEFAULT_EWMA_ALPHA_LONG, DEFAULT_EWMA_ALPHA_MID, DEFAULT_EWMA_ALPHA_SHORT,
+    };
     use assert_approx_eq::assert_approx_eq;
+    use std::vec::Vec;
 
     #[test]
     fn float_basic() {
@@ -210,4 +638,249 @@ mod tests {
         assert_eq!(range.end, 999);
         assert_eq!(range.start, 0);
     }
+
+    #[test]
+    fn multi_scale_float() {
+        let mut tracko: MultiScaleEwmaFilter<FloatSeriesEwmaFilter<f32>> =
+            MultiScaleEwmaFilter::with_float_alphas(
+                DEFAULT_EWMA_ALPHA_SHORT,
+                DEFAULT_EWMA_ALPHA_MID,
+                DEFAULT_EWMA_ALPHA_LONG,
+            );
+        for i in 0..1000 {
+            tracko.push_sample(i as f32);
+        }
+        // faster alpha fades to the recent tail quicker than a slower one
+        assert!(tracko.short().ewma_average() > tracko.mid().ewma_average());
+        assert!(tracko.mid().ewma_average() > tracko.long().ewma_average());
+    }
+
+    #[test]
+    fn multi_scale_int() {
+        let mut tracko: MultiScaleEwmaFilter<IntSeriesEwmaFilter<i64>> =
+            MultiScaleEwmaFilter::with_int_alphas(1, 10, 1, 100, 1, 1000);
+        for i in 0..1000 {
+            tracko.push_sample(i);
+        }
+        // faster alpha fades to the recent tail quicker than a slower one
+        assert!(tracko.short().ewma_average() > tracko.mid().ewma_average());
+        assert!(tracko.mid().ewma_average() > tracko.long().ewma_average());
+    }
+
+    #[test]
+    fn float_variance_tracks_spread() {
+        let mut steady: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        let mut noisy: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        for i in 0..200 {
+            steady.push_sample(10.0);
+            noisy.push_sample(if i % 2 == 0 { 0.0 } else { 20.0 });
+        }
+        assert_approx_eq!(steady.ewma_variance(), 0.0, 1e-6);
+        assert!(noisy.ewma_variance() > steady.ewma_variance());
+        assert_approx_eq!(noisy.ewma_std_dev(), noisy.ewma_variance().sqrt(), 1e-6);
+    }
+
+    #[test]
+    fn integer_variance_tracks_spread() {
+        let mut steady: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        let mut noisy: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        for i in 0..200 {
+            steady.push_sample(10);
+            noisy.push_sample(if i % 2 == 0 { 0 } else { 20 });
+        }
+        assert_eq!(steady.ewma_variance(), 0);
+        assert!(noisy.ewma_variance() > steady.ewma_variance());
+        assert_eq!(steady.ewma_std_dev(), 0);
+        // isqrt rounds down, so the std dev squared lands on or just under the variance
+        let std_dev = noisy.ewma_std_dev();
+        assert!(std_dev * std_dev <= noisy.ewma_variance());
+        assert!((std_dev + 1) * (std_dev + 1) > noisy.ewma_variance());
+    }
+
+    #[test]
+    fn time_weighted_push_sample_matches_unit_dt() {
+        let mut via_push_sample: TimeWeightedEwmaFilter<f32> = TimeWeightedEwmaFilter::new(10.0);
+        let mut via_push_sample_at: TimeWeightedEwmaFilter<f32> = TimeWeightedEwmaFilter::new(10.0);
+        for i in 0..50 {
+            via_push_sample.push_sample(i as f32);
+            via_push_sample_at.push_sample_at(i as f32, 1.0);
+        }
+        // push_sample() assumes one unit of elapsed time since the previous sample
+        assert_approx_eq!(
+            via_push_sample.ewma_average(),
+            via_push_sample_at.ewma_average(),
+            1e-6
+        );
+    }
+
+    #[test]
+    fn time_weighted_decays_more_with_larger_gaps() {
+        let mut timed: TimeWeightedEwmaFilter<f32> = TimeWeightedEwmaFilter::new(10.0);
+        timed.push_sample_at(0.0, 1.0);
+        timed.push_sample_at(100.0, 1.0);
+        let small_gap_average = timed.ewma_average();
+
+        let mut timed: TimeWeightedEwmaFilter<f32> = TimeWeightedEwmaFilter::new(10.0);
+        timed.push_sample_at(0.0, 1.0);
+        timed.push_sample_at(100.0, 50.0);
+        let large_gap_average = timed.ewma_average();
+
+        // a longer gap means more elapsed-time decay, so the average moves further
+        // toward the new sample
+        assert!(large_gap_average > small_gap_average);
+    }
+
+    #[test]
+    fn adjusted_converges_to_unadjusted_recursive_ewma() {
+        let mut adjusted: AdjustedEwmaFilter<f32> = AdjustedEwmaFilter::new(0.1);
+        let mut unadjusted: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        for i in 0..500 {
+            adjusted.push_sample(i as f32);
+            unadjusted.push_sample(i as f32);
+        }
+        assert_approx_eq!(
+            adjusted.ewma_average(),
+            unadjusted.ewma_average(),
+            1f32
+        );
+    }
+
+    #[test]
+    fn adjusted_is_not_anchored_to_first_sample_during_warmup() {
+        let mut adjusted: AdjustedEwmaFilter<f32> = AdjustedEwmaFilter::new(0.5);
+        assert_approx_eq!(adjusted.push_sample(0.0), 0.0, 1e-6);
+        // with the unadjusted filter the next value only moves half way toward 10.0,
+        // but the adjusted filter weighs the new sample by 1 and the old one by
+        // (1 - alpha) = 0.5, normalized by their sum: 10.0 / 1.5
+        let average = adjusted.push_sample(10.0);
+        assert_approx_eq!(average, 6.667, 1e-2);
+    }
+
+    #[test]
+    fn reset_returns_filter_to_seed_state() {
+        let mut tracko: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        for i in 0..100 {
+            tracko.push_sample(i as f32);
+        }
+        tracko.reset();
+        assert_eq!(tracko.ewma_average(), 0.0);
+        assert_eq!(tracko.ewma_variance(), 0.0);
+        let range = tracko.local_range();
+        assert_eq!(range.start, 0.0);
+        assert_eq!(range.end, 0.0);
+
+        // and the filter can be reused as if freshly constructed
+        assert_eq!(tracko.push_sample(42.0), 42.0);
+    }
+
+    #[test]
+    fn reset_returns_int_filter_to_seed_state() {
+        let mut tracko: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        for i in 0..100 {
+            tracko.push_sample(i);
+        }
+        tracko.reset();
+        assert_eq!(tracko.ewma_average(), 0);
+        assert_eq!(tracko.ewma_variance(), 0);
+        let range = tracko.local_range();
+        assert_eq!(range.start, 0);
+        assert_eq!(range.end, 0);
+
+        // and the filter can be reused as if freshly constructed
+        assert_eq!(tracko.push_sample(42), 42);
+    }
+
+    #[test]
+    fn push_iter_folds_samples_and_returns_final_average() {
+        let mut one_by_one: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        let mut via_iter: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        let samples: Vec<f32> = (0..100).map(|i| i as f32).collect();
+
+        let mut last = 0.0;
+        for &s in &samples {
+            last = one_by_one.push_sample(s);
+        }
+        let folded = via_iter.push_iter(samples);
+
+        assert_eq!(folded, last);
+    }
+
+    #[test]
+    fn push_iter_int_folds_samples_and_returns_final_average() {
+        let mut one_by_one: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        let mut via_iter: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        let samples: Vec<i64> = (0..100).collect();
+
+        let mut last = 0;
+        for &s in &samples {
+            last = one_by_one.push_sample(s);
+        }
+        let folded = via_iter.push_iter(samples);
+
+        assert_eq!(folded, last);
+    }
+
+    #[test]
+    fn ewma_over_matches_equivalent_filter() {
+        let samples: Vec<f32> = (0..100).map(|i| i as f32).collect();
+        let mut filter: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.2);
+        let expected = filter.push_iter(samples.clone());
+
+        assert_eq!(ewma_over(samples, 0.2), expected);
+    }
+
+    #[test]
+    fn ewma_over_int_matches_equivalent_filter() {
+        let samples: Vec<i64> = (0..100).collect();
+        let mut filter: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 5);
+        let expected = filter.push_iter(samples.clone());
+
+        assert_eq!(ewma_over_int(samples, 1, 5), expected);
+    }
+
+    #[test]
+    fn push_optional_sample_leaves_mean_unchanged_on_none() {
+        let mut tracko: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.1);
+        tracko.push_optional_sample(Some(10.0));
+        let average_before = tracko.ewma_average();
+
+        let returned = tracko.push_optional_sample(None);
+        assert_eq!(returned, average_before);
+        assert_eq!(tracko.ewma_average(), average_before);
+    }
+
+    #[test]
+    fn push_optional_sample_fades_extrema_toward_average() {
+        let mut tracko: FloatSeriesEwmaFilter<f32> = FloatSeriesEwmaFilter::new(0.5);
+        tracko.push_optional_sample(Some(0.0));
+        tracko.push_optional_sample(Some(100.0));
+        let max_before = tracko.local_range().end;
+
+        tracko.push_optional_sample(None);
+        let max_after = tracko.local_range().end;
+
+        assert!(max_after < max_before);
+    }
+
+    #[test]
+    fn push_optional_sample_int_behaves_like_push_sample_for_some() {
+        let mut via_optional: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        let mut via_push_sample: IntSeriesEwmaFilter<i64> = IntSeriesEwmaFilter::new(1, 10);
+        for i in 0..50 {
+            via_optional.push_optional_sample(Some(i));
+            via_push_sample.push_sample(i);
+        }
+        assert_eq!(via_optional.ewma_average(), via_push_sample.ewma_average());
+    }
+
+    #[test]
+    fn push_optional_sample_unsigned_does_not_underflow_when_max_fades_down() {
+        let mut tracko: IntSeriesEwmaFilter<u32> = IntSeriesEwmaFilter::new(1, 10);
+        tracko.push_sample(100);
+        tracko.push_sample(200);
+        // local_max (200) is now above the average, so the None-branch fade must
+        // subtract, not add, without underflowing the unsigned local_max/local_min
+        let average = tracko.push_optional_sample(None);
+        assert_eq!(average, tracko.ewma_average());
+    }
 }